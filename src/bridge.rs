@@ -0,0 +1,247 @@
+use crate::observability::{Metrics, Outcome};
+use crate::retry::{send_with_retry, RetryConfig};
+use crate::sanitize_and_checksum;
+use make87::{get_provider, get_requester, resolve_endpoint_name};
+use ros2_client::ros2::Qos;
+use ros2_client::{Name, Node, Service as RosService, ServiceMapping, ServiceTypeName};
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration as StdDuration, Instant};
+use tokio::runtime::Handle;
+use tokio::time::timeout;
+use tracing::{error, info, info_span, Instrument};
+use uuid::Uuid;
+
+/// Maps one make87 request/response pair onto one ROS2 service, so a new
+/// service/message pair can be bridged by providing a small impl of this
+/// trait instead of rewriting `main`.
+pub trait ServiceBridge {
+    type Make87Request: Debug + Clone + Send + 'static;
+    type Make87Response: Debug + Send + 'static;
+    type Ros: RosService + 'static;
+
+    /// The ROS2 service type name, e.g. `("example_interfaces", "AddTwoInts")`.
+    fn ros_service_type_name() -> ServiceTypeName;
+
+    /// The response returned when the ROS2 round-trip fails after retries.
+    fn error_response() -> Self::Make87Response;
+
+    fn to_ros(make87_request: Self::Make87Request) -> <Self::Ros as RosService>::Request;
+    fn from_ros(ros_response: <Self::Ros as RosService>::Response) -> Self::Make87Response;
+
+    fn from_ros_request(ros_request: <Self::Ros as RosService>::Request) -> Self::Make87Request;
+    fn to_ros_response(make87_response: Self::Make87Response) -> <Self::Ros as RosService>::Response;
+}
+
+/// Proxies a make87 provider endpoint into a ROS2 service client, using `B`
+/// to map requests and responses across the bridge.
+pub async fn run_bridge<B>(
+    node: &mut Node,
+    service_qos: Qos,
+    retry_config: RetryConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    B: ServiceBridge,
+    <B::Ros as RosService>::Response: Debug,
+{
+    let ros_client_name = resolve_endpoint_name("REQUESTER_ENDPOINT")
+        .map(|name| sanitize_and_checksum(&name)) // Prefix and replace '.' with '_'
+        .ok_or_else(|| "Failed to resolve topic name REQUESTER_ENDPOINT")?;
+
+    let proxy_ros_client = Arc::new(node.create_client::<B::Ros>(
+        ServiceMapping::Enhanced,
+        &Name::new("/", &ros_client_name)?,
+        &B::ros_service_type_name(),
+        service_qos.clone(),
+        service_qos,
+    )?);
+
+    let make87_endpoint_name = resolve_endpoint_name("PROVIDER_ENDPOINT")
+        .ok_or_else(|| "Failed to resolve topic name PROVIDER_ENDPOINT")?;
+
+    let proxy_make87_provider = Arc::new(
+        get_provider::<B::Make87Request, B::Make87Response>(make87_endpoint_name.clone())
+            .ok_or_else(|| "Failed to get provider for PROVIDER_ENDPOINT")?,
+    );
+
+    let metrics = Metrics::new();
+    metrics.spawn_periodic_logger(std::time::Duration::from_secs(60));
+
+    proxy_make87_provider
+        .provide_async(move |req: B::Make87Request| {
+            let client = Arc::clone(&proxy_ros_client);
+            let retry_config = retry_config.clone();
+            let metrics = Arc::clone(&metrics);
+            let ros_service = ros_client_name.clone();
+            let endpoint = make87_endpoint_name.clone();
+
+            let correlation_id = Uuid::new_v4();
+            let span = info_span!(
+                "bridge_request",
+                correlation_id = %correlation_id,
+                ros_service,
+                endpoint,
+                duration_ms = tracing::field::Empty,
+                retry_attempts = tracing::field::Empty,
+                outcome = tracing::field::Empty,
+            );
+
+            async move {
+                let arrived_at = Instant::now();
+                info!(?req, "request arrived from make87");
+
+                let sent_at = Instant::now();
+                let outcome = send_with_retry(&client, || B::to_ros(req.clone()), &retry_config).await;
+                let response_at = Instant::now();
+
+                let duration = response_at.duration_since(arrived_at);
+                let send_to_response = response_at.duration_since(sent_at);
+                tracing::Span::current().record("duration_ms", duration.as_millis() as u64);
+                tracing::Span::current().record("retry_attempts", outcome.attempts);
+
+                match outcome.result {
+                    Ok(ros_response) => {
+                        tracing::Span::current().record("outcome", Outcome::Success.as_str());
+                        info!(
+                            ?ros_response,
+                            round_trip_ms = send_to_response.as_millis() as u64,
+                            "bridged request succeeded"
+                        );
+                        metrics.record(duration, Outcome::Success);
+                        B::from_ros(ros_response)
+                    }
+                    Err(e) => {
+                        tracing::Span::current().record("outcome", Outcome::Error.as_str());
+                        error!(error = %e, "bridged request failed after retries");
+                        metrics.record(duration, Outcome::Error);
+                        B::error_response()
+                    }
+                }
+            }
+            .instrument(span)
+        })
+        .await
+        .unwrap();
+
+    Ok(())
+}
+
+/// Proxies a ROS2 service server into a make87 requester, using `B` to map
+/// requests and responses across the bridge. Runs as a background task
+/// alongside the node spinner.
+pub async fn run_reverse_bridge<B>(
+    node: &mut Node,
+    service_qos: Qos,
+    retry_config: RetryConfig,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+    B: ServiceBridge,
+    <B::Ros as RosService>::Request: Debug,
+{
+    let ros_server_name = resolve_endpoint_name("REQUESTER_ENDPOINT")
+        .map(|name| sanitize_and_checksum(&name)) // Prefix and replace '.' with '_'
+        .ok_or_else(|| "Failed to resolve topic name REQUESTER_ENDPOINT")?;
+
+    let ros_server = Arc::new(node.create_server::<B::Ros>(
+        ServiceMapping::Enhanced,
+        &Name::new("/", &ros_server_name)?,
+        &B::ros_service_type_name(),
+        service_qos.clone(),
+        service_qos,
+    )?);
+
+    let make87_endpoint_name = resolve_endpoint_name("PROVIDER_ENDPOINT")
+        .ok_or_else(|| "Failed to resolve topic name PROVIDER_ENDPOINT")?;
+
+    let proxy_make87_requester = Arc::new(
+        get_requester::<B::Make87Request, B::Make87Response>(make87_endpoint_name.clone())
+            .ok_or_else(|| "Failed to get requester for PROVIDER_ENDPOINT")?,
+    );
+
+    let metrics = Metrics::new();
+    metrics.spawn_periodic_logger(std::time::Duration::from_secs(60));
+
+    Handle::current().spawn(async move {
+        loop {
+            let (request_id, ros_request) = match ros_server.async_receive_request().await {
+                Ok(pair) => pair,
+                Err(e) => {
+                    error!(error = ?e, "failed to receive ROS2 request");
+                    // Avoid spinning the loop at full CPU (and log volume) on a
+                    // persistently failing receive.
+                    tokio::time::sleep(retry_config.backoff_delay(0)).await;
+                    continue;
+                }
+            };
+
+            // Handle each request on its own task so a slow or hung make87 endpoint
+            // can't wedge the loop and block receiving the next ROS2 request.
+            let ros_server = Arc::clone(&ros_server);
+            let proxy_make87_requester = Arc::clone(&proxy_make87_requester);
+            let metrics = Arc::clone(&metrics);
+            let ros_service = ros_server_name.clone();
+            let endpoint = make87_endpoint_name.clone();
+            let io_timeout = StdDuration::from_millis(retry_config.io_timeout_ms);
+
+            Handle::current().spawn(async move {
+                let correlation_id = Uuid::new_v4();
+                let span = info_span!(
+                    "reverse_bridge_request",
+                    correlation_id = %correlation_id,
+                    ros_service,
+                    endpoint,
+                    duration_ms = tracing::field::Empty,
+                    outcome = tracing::field::Empty,
+                );
+
+                // Entering the span and holding the guard across the `.await` below would
+                // corrupt the thread-local span stack once this task yields to another one
+                // (the spinner, the metrics logger, a concurrent request) on the same
+                // worker thread, so the request body is instrumented instead.
+                let outcome = async {
+                    let arrived_at = Instant::now();
+                    info!(?ros_request, "request arrived from ROS2");
+
+                    let make87_request = B::from_ros_request(ros_request);
+
+                    let response_result =
+                        timeout(io_timeout, proxy_make87_requester.request(make87_request)).await;
+                    let duration = arrived_at.elapsed();
+                    tracing::Span::current().record("duration_ms", duration.as_millis() as u64);
+
+                    match response_result {
+                        Ok(Ok(make87_response)) => {
+                            tracing::Span::current().record("outcome", Outcome::Success.as_str());
+                            metrics.record(duration, Outcome::Success);
+                            Some(B::to_ros_response(make87_response))
+                        }
+                        Ok(Err(e)) => {
+                            tracing::Span::current().record("outcome", Outcome::Error.as_str());
+                            metrics.record(duration, Outcome::Error);
+                            error!(error = ?e, "make87 requester error");
+                            None
+                        }
+                        Err(_) => {
+                            tracing::Span::current().record("outcome", Outcome::Error.as_str());
+                            metrics.record(duration, Outcome::Error);
+                            error!("make87 requester call timed out");
+                            None
+                        }
+                    }
+                }
+                .instrument(span)
+                .await;
+
+                let Some(response) = outcome else {
+                    return;
+                };
+
+                if let Err(e) = ros_server.send_response(request_id, response) {
+                    error!(error = ?e, "failed to send ROS2 response");
+                }
+            });
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+/// Which leg of the make87<->ROS2 bridge this process runs, selected via the
+/// `BRIDGE_DIRECTION` environment variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BridgeDirection {
+    /// make87 provider -> ROS2 client (the original template behavior).
+    Make87ToRos2,
+    /// ROS2 service server -> make87 requester.
+    Ros2ToMake87,
+}
+
+impl BridgeDirection {
+    /// Reads `BRIDGE_DIRECTION`, defaulting to [`BridgeDirection::Make87ToRos2`]
+    /// when unset or unrecognized. Warns on a non-empty but unrecognized value,
+    /// since silently defaulting there usually means a typo.
+    pub fn from_env() -> Self {
+        let raw = std::env::var("BRIDGE_DIRECTION").unwrap_or_default();
+
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "ros2_to_make87" => BridgeDirection::Ros2ToMake87,
+            "" | "make87_to_ros2" => BridgeDirection::Make87ToRos2,
+            _ => {
+                tracing::warn!(
+                    value = raw,
+                    "unrecognized BRIDGE_DIRECTION value, defaulting to make87_to_ros2"
+                );
+                BridgeDirection::Make87ToRos2
+            }
+        }
+    }
+}
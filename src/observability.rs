@@ -0,0 +1,103 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::runtime::Handle;
+use tracing::info;
+
+/// Whether a bridged request ultimately succeeded or failed, for metrics and
+/// span field purposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Success,
+    Error,
+}
+
+impl Outcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Outcome::Success => "success",
+            Outcome::Error => "error",
+        }
+    }
+}
+
+/// Aggregate request/error counters and a bounded latency sample set for the
+/// bridge, logged periodically so operators can see throughput and tail
+/// latency without scraping every individual span.
+#[derive(Default)]
+pub struct Metrics {
+    request_count: AtomicU64,
+    error_count: AtomicU64,
+    latencies_ms: Mutex<Vec<u64>>,
+}
+
+/// Latency samples are capped to bound memory on a long-running bridge; the
+/// aggregate counters are unaffected by the cap.
+const MAX_LATENCY_SAMPLES: usize = 10_000;
+
+pub struct MetricsSnapshot {
+    pub request_count: u64,
+    pub error_count: u64,
+    pub p50_ms: u64,
+    pub p99_ms: u64,
+}
+
+impl Metrics {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    pub fn record(&self, duration: Duration, outcome: Outcome) {
+        self.request_count.fetch_add(1, Ordering::Relaxed);
+        if outcome == Outcome::Error {
+            self.error_count.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut latencies = self.latencies_ms.lock().unwrap();
+        latencies.push(duration.as_millis() as u64);
+        if latencies.len() > MAX_LATENCY_SAMPLES {
+            let excess = latencies.len() - MAX_LATENCY_SAMPLES;
+            latencies.drain(0..excess);
+        }
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let mut latencies = self.latencies_ms.lock().unwrap().clone();
+        latencies.sort_unstable();
+
+        MetricsSnapshot {
+            request_count: self.request_count.load(Ordering::Relaxed),
+            error_count: self.error_count.load(Ordering::Relaxed),
+            p50_ms: percentile(&latencies, 0.50),
+            p99_ms: percentile(&latencies, 0.99),
+        }
+    }
+
+    /// Spawns a background task that logs an aggregate snapshot every
+    /// `interval`, alongside the node spinner and bridge task.
+    pub fn spawn_periodic_logger(self: &Arc<Self>, interval: Duration) {
+        let metrics = Arc::clone(self);
+        Handle::current().spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let snapshot = metrics.snapshot();
+                info!(
+                    request_count = snapshot.request_count,
+                    error_count = snapshot.error_count,
+                    p50_ms = snapshot.p50_ms,
+                    p99_ms = snapshot.p99_ms,
+                    "bridge metrics"
+                );
+            }
+        });
+    }
+}
+
+fn percentile(sorted_latencies_ms: &[u64], fraction: f64) -> u64 {
+    if sorted_latencies_ms.is_empty() {
+        return 0;
+    }
+    let index = ((sorted_latencies_ms.len() - 1) as f64 * fraction).round() as usize;
+    sorted_latencies_ms[index]
+}
@@ -0,0 +1,126 @@
+use ros2_client::ros2::{policy, Qos, QosPolicyBuilder};
+
+/// Parsed QoS knobs for the bridged ROS2 client, resolved from environment
+/// variables so a deployment can match whatever profile the ROS2 peer
+/// advertises without editing the template.
+///
+/// Defaults mirror the profile this bridge used before it was configurable:
+/// reliable delivery, `KeepLast(1)` history, volatile durability and no
+/// deadline/lifespan bound.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QosConfig {
+    pub reliable: bool,
+    pub max_blocking_time_ms: u64,
+    pub keep_all_history: bool,
+    pub history_depth: i32,
+    pub transient_local_durability: bool,
+    pub deadline_ms: Option<u64>,
+    pub lifespan_ms: Option<u64>,
+}
+
+impl Default for QosConfig {
+    fn default() -> Self {
+        Self {
+            reliable: true,
+            max_blocking_time_ms: 100,
+            keep_all_history: false,
+            history_depth: 1,
+            transient_local_durability: false,
+            deadline_ms: None,
+            lifespan_ms: None,
+        }
+    }
+}
+
+impl QosConfig {
+    /// Reads `REQUESTER_QOS_*` environment variables, falling back to
+    /// [`QosConfig::default`] for anything unset or unparsable.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        Self {
+            reliable: env_bool("REQUESTER_QOS_RELIABLE", defaults.reliable),
+            max_blocking_time_ms: env_u64(
+                "REQUESTER_QOS_MAX_BLOCKING_TIME_MS",
+                defaults.max_blocking_time_ms,
+            ),
+            keep_all_history: env_bool("REQUESTER_QOS_KEEP_ALL_HISTORY", defaults.keep_all_history),
+            history_depth: env_i32("REQUESTER_QOS_HISTORY_DEPTH", defaults.history_depth),
+            transient_local_durability: env_bool(
+                "REQUESTER_QOS_TRANSIENT_LOCAL",
+                defaults.transient_local_durability,
+            ),
+            deadline_ms: env_opt_u64("REQUESTER_QOS_DEADLINE_MS"),
+            lifespan_ms: env_opt_u64("REQUESTER_QOS_LIFESPAN_MS"),
+        }
+    }
+
+    /// Builds the `ros2_client` QoS profile for this config, to be applied to
+    /// both the request and response sides of `create_client`/`create_server`.
+    pub fn build(&self) -> Qos {
+        let mut builder = QosPolicyBuilder::new()
+            .reliability(if self.reliable {
+                policy::Reliability::Reliable {
+                    max_blocking_time: ros2_client::ros2::Duration::from_millis(
+                        self.max_blocking_time_ms,
+                    ),
+                }
+            } else {
+                policy::Reliability::BestEffort
+            })
+            .history(if self.keep_all_history {
+                policy::History::KeepAll
+            } else {
+                policy::History::KeepLast {
+                    depth: self.history_depth,
+                }
+            });
+
+        if self.transient_local_durability {
+            builder = builder.durability(policy::Durability::TransientLocal);
+        }
+
+        if let Some(deadline_ms) = self.deadline_ms {
+            builder = builder.deadline(policy::Deadline(ros2_client::ros2::Duration::from_millis(
+                deadline_ms,
+            )));
+        }
+
+        if let Some(lifespan_ms) = self.lifespan_ms {
+            builder = builder.lifespan(policy::Lifespan {
+                duration: ros2_client::ros2::Duration::from_millis(lifespan_ms),
+            });
+        }
+
+        builder.build()
+    }
+}
+
+fn env_bool(key: &str, default: bool) -> bool {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| match v.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" => Some(true),
+            "0" | "false" | "no" => Some(false),
+            _ => None,
+        })
+        .unwrap_or(default)
+}
+
+fn env_u64(key: &str, default: u64) -> u64 {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(default)
+}
+
+fn env_i32(key: &str, default: i32) -> i32 {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(default)
+}
+
+fn env_opt_u64(key: &str) -> Option<u64> {
+    std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+}
@@ -0,0 +1,151 @@
+use ros2_client::Service;
+use std::time::Duration as StdDuration;
+use tokio::time::timeout;
+use tracing::debug;
+
+/// Timeout and backoff knobs for a bridged ROS2 request/response round-trip.
+///
+/// Defaults reproduce the bridge's original behavior: a single attempt
+/// bounded by a 100ms window on both the send and the receive side, with no
+/// retries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryConfig {
+    pub connect_timeout_ms: u64,
+    pub io_timeout_ms: u64,
+    pub max_retries: u32,
+    pub backoff_base_ms: u64,
+    pub max_backoff_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout_ms: 100,
+            io_timeout_ms: 100,
+            max_retries: 0,
+            backoff_base_ms: 100,
+            max_backoff_ms: 5_000,
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Reads `REQUESTER_CONNECT_TIMEOUT_MS`, `REQUESTER_IO_TIMEOUT_MS`,
+    /// `REQUESTER_MAX_RETRIES` and `REQUESTER_BACKOFF_BASE_MS` /
+    /// `REQUESTER_MAX_BACKOFF_MS`, falling back to [`RetryConfig::default`].
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        Self {
+            connect_timeout_ms: env_u64(
+                "REQUESTER_CONNECT_TIMEOUT_MS",
+                defaults.connect_timeout_ms,
+            ),
+            io_timeout_ms: env_u64("REQUESTER_IO_TIMEOUT_MS", defaults.io_timeout_ms),
+            max_retries: env_u32("REQUESTER_MAX_RETRIES", defaults.max_retries),
+            backoff_base_ms: env_u64("REQUESTER_BACKOFF_BASE_MS", defaults.backoff_base_ms),
+            max_backoff_ms: env_u64("REQUESTER_MAX_BACKOFF_MS", defaults.max_backoff_ms),
+        }
+    }
+
+    /// Exponential backoff delay before the given (zero-indexed) retry
+    /// attempt, capped at `max_backoff_ms`. Deliberately no jitter: this is
+    /// a single bridged client hitting a single ROS2 service, so there's no
+    /// thundering-herd risk to smear out.
+    pub fn backoff_delay(&self, attempt: u32) -> StdDuration {
+        let delay_ms = self
+            .backoff_base_ms
+            .saturating_mul(1u64 << attempt.min(32))
+            .min(self.max_backoff_ms);
+        StdDuration::from_millis(delay_ms)
+    }
+}
+
+/// The result of [`send_with_retry`] plus how many attempts it took, so
+/// callers can surface the retry count alongside the outcome (e.g. in a
+/// tracing span).
+pub struct RetryOutcome<T> {
+    pub result: Result<T, String>,
+    pub attempts: u32,
+}
+
+/// Sends a fresh request (built by `make_request` on every attempt, so each
+/// retry gets its own request id) and awaits the response, bounding both the
+/// send and the receive by the configured timeouts. On timeout or transport
+/// error it retries up to `max_retries` times with exponential backoff
+/// before giving up.
+pub async fn send_with_retry<S, F>(
+    client: &ros2_client::Client<S>,
+    mut make_request: F,
+    config: &RetryConfig,
+) -> RetryOutcome<S::Response>
+where
+    S: Service,
+    F: FnMut() -> S::Request,
+{
+    let mut last_error = String::new();
+
+    for attempt in 0..=config.max_retries {
+        if attempt > 0 {
+            debug!(
+                attempt,
+                max_retries = config.max_retries,
+                last_error,
+                "retrying ROS2 request"
+            );
+            tokio::time::sleep(config.backoff_delay(attempt - 1)).await;
+        }
+
+        let req_id = match timeout(
+            StdDuration::from_millis(config.connect_timeout_ms),
+            client.async_send_request(make_request()),
+        )
+        .await
+        {
+            Ok(Ok(req_id)) => req_id,
+            Ok(Err(e)) => {
+                last_error = format!("request sending error: {e:?}");
+                continue;
+            }
+            Err(_) => {
+                last_error = "timed out sending request".to_string();
+                continue;
+            }
+        };
+
+        match timeout(
+            StdDuration::from_millis(config.io_timeout_ms),
+            client.async_receive_response(req_id),
+        )
+        .await
+        {
+            Ok(Ok(response)) => {
+                return RetryOutcome {
+                    result: Ok(response),
+                    attempts: attempt + 1,
+                }
+            }
+            Ok(Err(e)) => last_error = format!("response error: {e:?}"),
+            Err(_) => last_error = "timed out waiting for response".to_string(),
+        }
+    }
+
+    RetryOutcome {
+        result: Err(last_error),
+        attempts: config.max_retries + 1,
+    }
+}
+
+fn env_u64(key: &str, default: u64) -> u64 {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(default)
+}
+
+fn env_u32(key: &str, default: u32) -> u32 {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(default)
+}
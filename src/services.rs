@@ -0,0 +1,58 @@
+use crate::bridge::ServiceBridge;
+use make87_messages::spatial::translation::{Translation1D, Translation2D};
+use make87_messages::well_known_types::Timestamp;
+use make87_messages::CurrentTime;
+use ros2_client::ServiceTypeName;
+use ros2_interfaces_rolling::example_interfaces::srv::{
+    AddTwoInts, AddTwoIntsRequest, AddTwoIntsResponse,
+};
+
+/// Bridges make87 `Translation2D`/`Translation1D` to the ROS2
+/// `example_interfaces/AddTwoInts` service, the pair this template shipped
+/// with originally.
+pub struct AddTwoIntsBridge;
+
+impl ServiceBridge for AddTwoIntsBridge {
+    type Make87Request = Translation2D;
+    type Make87Response = Translation1D;
+    type Ros = AddTwoInts;
+
+    fn ros_service_type_name() -> ServiceTypeName {
+        ServiceTypeName::new("example_interfaces", "AddTwoInts")
+    }
+
+    fn error_response() -> Translation1D {
+        Translation1D {
+            timestamp: None,
+            x: 0.0,
+        }
+    }
+
+    fn to_ros(make87_request: Translation2D) -> AddTwoIntsRequest {
+        AddTwoIntsRequest {
+            a: make87_request.x as i64,
+            b: make87_request.y as i64,
+        }
+    }
+
+    fn from_ros(ros_response: AddTwoIntsResponse) -> Translation1D {
+        Translation1D {
+            timestamp: Timestamp::get_current_time(),
+            x: ros_response.sum as f32,
+        }
+    }
+
+    fn from_ros_request(ros_request: AddTwoIntsRequest) -> Translation2D {
+        Translation2D {
+            timestamp: Timestamp::get_current_time(),
+            x: ros_request.a as f32,
+            y: ros_request.b as f32,
+        }
+    }
+
+    fn to_ros_response(make87_response: Translation1D) -> AddTwoIntsResponse {
+        AddTwoIntsResponse {
+            sum: make87_response.x as i64,
+        }
+    }
+}